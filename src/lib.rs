@@ -0,0 +1,715 @@
+use std::fmt;
+use std::io::{BufReader, Read};
+
+/// Streams source characters one at a time instead of indexing into a
+/// fully-buffered `String`, so a single pass over a file is O(n) rather
+/// than O(n^2), and works against any `Read` (stdin, sockets, ...).
+pub struct Parser<R: Read> {
+    reader: BufReader<R>,
+    read_stack: Vec<char>,
+    file_name: Option<String>,
+    line: usize,
+    column: usize,
+    options: ParserOptions,
+}
+
+/// How string escapes are interpreted by `read_str`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringEscapeMode {
+    /// Characters between quotes are taken verbatim; no `\` is special.
+    None,
+    /// `\n`, `\t`, `\"`, `\\`, `\xNN`, `\u{...}`, ... are interpreted.
+    Standard,
+}
+
+/// Configures which Lisp dialect `Parser` reads: which bracket pairs
+/// delimit lists, whether `nil`/`t` are literals rather than plain
+/// symbols, and how string escapes are handled. Mirrors lexpr's
+/// `Options`, letting one reader serve Elisp-, Scheme-, and
+/// default-style sources by construction rather than by forking code.
+#[derive(Debug, Clone)]
+pub struct ParserOptions {
+    /// Open/close pairs accepted as list delimiters, tried in order.
+    list_brackets: Vec<(char, char)>,
+    /// Promote the symbol `nil` to an empty `LIST` object (Elisp-style).
+    nil_is_empty_list: bool,
+    /// Promote the symbols `t`/`#t`/`#f` to `BOOLEAN` objects.
+    bool_literals: bool,
+    string_escapes: StringEscapeMode,
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        ParserOptions {
+            list_brackets: vec![('(', ')')],
+            nil_is_empty_list: false,
+            bool_literals: false,
+            string_escapes: StringEscapeMode::Standard,
+        }
+    }
+}
+
+impl ParserOptions {
+    pub fn with_square_brackets(mut self) -> Self {
+        self.list_brackets.push(('[', ']'));
+        self
+    }
+
+    pub fn with_curly_braces(mut self) -> Self {
+        self.list_brackets.push(('{', '}'));
+        self
+    }
+
+    /// Enable Elisp-style `nil` as an empty-list literal.
+    pub fn with_nil_as_empty_list(mut self) -> Self {
+        self.nil_is_empty_list = true;
+        self
+    }
+
+    /// Enable `t`/`#t`/`#f` as boolean literals.
+    pub fn with_bool_literals(mut self) -> Self {
+        self.bool_literals = true;
+        self
+    }
+
+    pub fn with_string_escapes(mut self, mode: StringEscapeMode) -> Self {
+        self.string_escapes = mode;
+        self
+    }
+
+    fn closing_bracket_for(&self, open: char) -> Option<char> {
+        self.list_brackets
+            .iter()
+            .find(|(o, _)| *o == open)
+            .map(|(_, close)| *close)
+    }
+}
+
+/// A syntax error tied to the exact source position that caused it,
+/// rendered as `file:line:col: message` (or `line:col: message` when no
+/// file name is known, e.g. when parsing from a string or stdin).
+#[derive(Debug)]
+pub struct ParseError {
+    pub file_name: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.file_name {
+            Some(name) => write!(f, "{}:{}:{}: {}", name, self.line, self.column, self.message),
+            None => write!(f, "{}:{}: {}", self.line, self.column, self.message),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ObjectType {
+    SYMBOL,
+    STRING,
+    NUMBER,
+    FLOAT,
+    BOOLEAN,
+    LIST,
+}
+
+#[derive(Debug)]
+pub enum ObjectVal {
+    ListValue(Vec<Object>),
+    IntegerValue(i32),
+    FloatValue(f64),
+    StringValue(String),
+}
+
+#[derive(Debug)]
+pub struct Object {
+    pub _type: ObjectType,
+    pub _val: Option<ObjectVal>,
+}
+
+fn create_object(_type: ObjectType) -> Object {
+    return Object { _type, _val: None };
+}
+
+pub fn create_sym_obj(content: &str) -> Object {
+    let mut obj = create_object(ObjectType::SYMBOL);
+    obj._val = Some(ObjectVal::StringValue(content.to_string()));
+    return obj;
+}
+
+pub fn create_bool_obj(value: bool) -> Object {
+    let mut obj = create_object(ObjectType::BOOLEAN);
+    obj._val = Some(ObjectVal::IntegerValue(value as i32));
+    return obj;
+}
+
+pub fn create_num_obj(content: i32) -> Object {
+    let mut obj = create_object(ObjectType::NUMBER);
+    obj._val = Some(ObjectVal::IntegerValue(content));
+    return obj;
+}
+
+pub fn create_float_obj(content: f64) -> Object {
+    let mut obj = create_object(ObjectType::FLOAT);
+    obj._val = Some(ObjectVal::FloatValue(content));
+    return obj;
+}
+
+pub fn create_str_obj(str: String) -> Object {
+    let mut obj = create_object(ObjectType::STRING);
+    obj._val = Some(ObjectVal::StringValue(str));
+    return obj;
+}
+
+pub fn create_list_obj(content: Vec<Object>) -> Object {
+    let mut obj = create_object(ObjectType::LIST);
+    obj._val = Some(ObjectVal::ListValue(content));
+    return obj;
+}
+
+impl<R: Read> Parser<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_options(reader, ParserOptions::default())
+    }
+
+    pub fn with_options(reader: R, options: ParserOptions) -> Self {
+        Parser {
+            reader: BufReader::new(reader),
+            read_stack: Vec::new(),
+            file_name: None,
+            line: 1,
+            column: 0,
+            options,
+        }
+    }
+
+    pub fn with_file_name(reader: R, file_name: &str) -> Self {
+        let mut parser = Self::new(reader);
+        parser.file_name = Some(file_name.to_string());
+        parser
+    }
+
+    /// Reads a single top-level expression, or `None` at EOF. Matching
+    /// lexpr's "read one S-expression" model, this can be called
+    /// repeatedly on the same `Parser` to pull successive expressions
+    /// off the same stream.
+    pub fn read_one(&mut self) -> Result<Option<Object>, ParseError> {
+        read_expr(self)
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            file_name: self.file_name.clone(),
+            line: self.line,
+            column: self.column,
+            message: message.into(),
+        }
+    }
+
+    /// Reads one UTF-8 scalar value directly off the underlying reader,
+    /// decoding multi-byte sequences from the leading byte's length, and
+    /// advances `line`/`column` so errors can point at this char. Malformed
+    /// UTF-8 is reported as a `ParseError` rather than panicking, so a
+    /// library caller handing us arbitrary bytes never takes the process
+    /// down with it.
+    fn read_raw_char(&mut self) -> Result<Option<char>, ParseError> {
+        let mut buf = [0u8; 4];
+        if self.reader.read_exact(&mut buf[..1]).is_err() {
+            return Ok(None);
+        }
+        let len = match buf[0] {
+            b if b & 0x80 == 0x00 => 1,
+            b if b & 0xE0 == 0xC0 => 2,
+            b if b & 0xF0 == 0xE0 => 3,
+            b if b & 0xF8 == 0xF0 => 4,
+            _ => 1,
+        };
+        if len > 1 {
+            self.reader
+                .read_exact(&mut buf[1..len])
+                .map_err(|_| self.error("invalid UTF-8 in input"))?;
+        }
+        let ch = std::str::from_utf8(&buf[..len])
+            .map_err(|_| self.error("invalid UTF-8 in input"))?
+            .chars()
+            .next();
+        if let Some(c) = ch {
+            if c == '\n' {
+                self.line += 1;
+                self.column = 0;
+            } else {
+                self.column += 1;
+            }
+        }
+        Ok(ch)
+    }
+
+    /// Pops a pushed-back char first so `unget_char` is always honoured,
+    /// then falls back to the reader; whitespace is dropped when asked.
+    fn get_char(&mut self, skip_whitespace: bool) -> Result<Option<char>, ParseError> {
+        loop {
+            let c = match self.read_stack.pop() {
+                Some(ch) => Some(ch),
+                None => self.read_raw_char()?,
+            };
+            match c {
+                Some(ch) if skip_whitespace && ch.is_whitespace() => continue,
+                other => return Ok(other),
+            }
+        }
+    }
+
+    fn unget_char(&mut self, c: char) {
+        self.read_stack.push(c);
+    }
+
+    /// `peek_char` is just get-then-unget, so callers never have to
+    /// special-case the pushback stack themselves.
+    fn peek_char(&mut self, skip_whitespace: bool) -> Result<Option<char>, ParseError> {
+        let c = self.get_char(skip_whitespace)?;
+        if let Some(ch) = c {
+            self.unget_char(ch);
+        }
+        Ok(c)
+    }
+}
+
+/// Parses every top-level expression off `reader` into a `Vec<Object>`.
+pub fn parse_reader<R: Read>(reader: R) -> Result<Vec<Object>, ParseError> {
+    let mut parser = Parser::new(reader);
+    let mut exprs = Vec::new();
+    while let Some(obj) = parser.read_one()? {
+        exprs.push(obj);
+    }
+    Ok(exprs)
+}
+
+/// Parses every top-level expression in `input` into a `Vec<Object>`.
+pub fn parse_str(input: &str) -> Result<Vec<Object>, ParseError> {
+    parse_reader(input.as_bytes())
+}
+
+fn is_in_base(chr: char, radix: u32) -> bool {
+    chr.is_digit(radix)
+}
+
+/// Parses an integer or float literal, `first` being either the leading
+/// digit already consumed by `read_expr` or a `-`/`+` sign it peeked
+/// ahead of a digit to disambiguate from a symbol. Handles `0x`/`0o`/`0b`
+/// radix prefixes (integers only) and decimal points/exponents (floats).
+fn read_number<R: Read>(parser: &mut Parser<R>, first: char) -> Result<Object, ParseError> {
+    let negative = first == '-';
+    let mut text = String::new();
+    if first == '-' || first == '+' {
+        let leading_digit = parser
+            .get_char(false)?
+            .ok_or_else(|| parser.error("invalid number literal: missing digits after sign"))?;
+        text.push(leading_digit);
+    } else {
+        text.push(first);
+    }
+
+    let mut radix = 10u32;
+    if text == "0" {
+        let radix_marker = match parser.peek_char(false)? {
+            Some('x') | Some('X') => Some(16),
+            Some('o') | Some('O') => Some(8),
+            Some('b') | Some('B') => Some(2),
+            _ => None,
+        };
+        if let Some(r) = radix_marker {
+            parser.get_char(false)?;
+            radix = r;
+            text.clear();
+        }
+    }
+
+    if radix != 10 {
+        while let Some(chr) = parser.get_char(false)? {
+            if !is_in_base(chr, radix) {
+                parser.unget_char(chr);
+                break;
+            }
+            text.push(chr);
+        }
+        if text.is_empty() {
+            return Err(parser.error("invalid number literal: no digits after radix prefix"));
+        }
+        let signed_text = if negative { format!("-{}", text) } else { text.clone() };
+        let value = i32::from_str_radix(&signed_text, radix)
+            .map_err(|_| parser.error(format!("not a number: {}", signed_text)))?;
+        return Ok(create_num_obj(value));
+    }
+
+    let mut is_float = false;
+    while let Some(chr) = parser.get_char(false)? {
+        if chr.is_ascii_digit() {
+            text.push(chr);
+        } else if chr == '.' && !is_float {
+            is_float = true;
+            text.push(chr);
+        } else if (chr == 'e' || chr == 'E') && !text.is_empty() {
+            is_float = true;
+            text.push(chr);
+            if matches!(parser.peek_char(false)?, Some('+') | Some('-')) {
+                text.push(parser.get_char(false)?.unwrap());
+            }
+        } else {
+            parser.unget_char(chr);
+            break;
+        }
+    }
+
+    if is_float {
+        let signed_text = if negative { format!("-{}", text) } else { text.clone() };
+        let value: f64 = signed_text
+            .parse()
+            .map_err(|_| parser.error(format!("not a number: {}", signed_text)))?;
+        Ok(create_float_obj(value))
+    } else {
+        let signed_text = if negative { format!("-{}", text) } else { text.clone() };
+        let value: i32 = signed_text
+            .parse()
+            .map_err(|_| parser.error(format!("not a number: {}", signed_text)))?;
+        Ok(create_num_obj(value))
+    }
+}
+
+fn read_list<R: Read>(parser: &mut Parser<R>, close: char) -> Result<Object, ParseError> {
+    let mut list: Vec<Object> = vec![];
+    loop {
+        match parser.peek_char(true)? {
+            None => return Err(parser.error(format!("unexpected EOF, expected '{}'", close))),
+            Some(c) if c == close => {
+                parser.get_char(true)?;
+                break;
+            }
+            Some(_) => {
+                let parsed_expr = read_expr(parser)?
+                    .ok_or_else(|| parser.error(format!("unexpected EOF, expected '{}'", close)))?;
+                list.push(parsed_expr);
+            }
+        }
+    }
+    Ok(create_list_obj(list))
+}
+
+fn is_symbol(chr: char) -> bool {
+    return chr.is_alphanumeric() || chr == '+' || chr == '-' || chr == '/' || chr == '*';
+}
+
+fn read_symbol<R: Read>(parser: &mut Parser<R>, first: char) -> Result<Object, ParseError> {
+    let mut symbol = String::from("");
+    symbol.push(first);
+    while let Some(chr) = parser.get_char(false)? {
+        if !is_symbol(chr) {
+            parser.unget_char(chr);
+            break;
+        }
+        symbol.push(chr);
+    }
+    Ok(create_sym_obj(&symbol))
+}
+
+fn read_str<R: Read>(parser: &mut Parser<R>) -> Result<Object, ParseError> {
+    let mut str = String::from("");
+    loop {
+        match parser.get_char(false)? {
+            None => return Err(parser.error("unterminated string literal")),
+            Some('"') => break,
+            Some('\\') if parser.options.string_escapes == StringEscapeMode::Standard => {
+                str.push(read_escape(parser)?);
+            }
+            Some(chr) => str.push(chr),
+        }
+    }
+    Ok(create_str_obj(str))
+}
+
+/// Reads the character(s) after a `\` inside a string literal: the
+/// single-char escapes, plus `\xNN` and `\u{...}` hex escapes.
+fn read_escape<R: Read>(parser: &mut Parser<R>) -> Result<char, ParseError> {
+    let chr = parser
+        .get_char(false)?
+        .ok_or_else(|| parser.error("unterminated string literal"))?;
+    match chr {
+        'n' => Ok('\n'),
+        't' => Ok('\t'),
+        'r' => Ok('\r'),
+        '0' => Ok('\0'),
+        '\\' => Ok('\\'),
+        '"' => Ok('"'),
+        'x' => read_hex_escape(parser, 2),
+        'u' => read_unicode_escape(parser),
+        other => Err(parser.error(format!("unknown string escape: \\{}", other))),
+    }
+}
+
+fn read_hex_digit<R: Read>(parser: &mut Parser<R>) -> Result<char, ParseError> {
+    let chr = parser
+        .get_char(false)?
+        .ok_or_else(|| parser.error("unterminated string literal"))?;
+    if !chr.is_ascii_hexdigit() {
+        return Err(parser.error(format!("invalid hex escape digit: {}", chr)));
+    }
+    Ok(chr)
+}
+
+fn read_hex_escape<R: Read>(parser: &mut Parser<R>, digits: usize) -> Result<char, ParseError> {
+    let mut text = String::new();
+    for _ in 0..digits {
+        text.push(read_hex_digit(parser)?);
+    }
+    let code = u32::from_str_radix(&text, 16).unwrap();
+    char::from_u32(code).ok_or_else(|| parser.error(format!("invalid \\x{} escape", text)))
+}
+
+fn read_unicode_escape<R: Read>(parser: &mut Parser<R>) -> Result<char, ParseError> {
+    let open = parser
+        .get_char(false)?
+        .ok_or_else(|| parser.error("unterminated string literal"))?;
+    if open != '{' {
+        return Err(parser.error("expected '{' after \\u"));
+    }
+    let mut text = String::new();
+    loop {
+        match parser.get_char(false)? {
+            None => return Err(parser.error("unterminated string literal")),
+            Some('}') => break,
+            Some(chr) if chr.is_ascii_hexdigit() => text.push(chr),
+            Some(chr) => return Err(parser.error(format!("invalid hex escape digit: {}", chr))),
+        }
+    }
+    let code = u32::from_str_radix(&text, 16)
+        .map_err(|_| parser.error(format!("invalid \\u{{{}}} escape", text)))?;
+    char::from_u32(code).ok_or_else(|| parser.error(format!("invalid \\u{{{}}} escape", text)))
+}
+
+/// Reads the form following a reader macro prefix and wraps it as
+/// `(symbol form)`, e.g. `'x` becomes `(quote x)`.
+fn read_quoted<R: Read>(parser: &mut Parser<R>, symbol: &str) -> Result<Object, ParseError> {
+    let inner = read_expr(parser)?
+        .ok_or_else(|| parser.error(format!("unexpected EOF after '{}'", symbol)))?;
+    Ok(create_list_obj(vec![create_sym_obj(symbol), inner]))
+}
+
+/// Promotes `nil`/`t` to their dialect-specific literal objects when the
+/// active `ParserOptions` ask for it; otherwise leaves the symbol as-is.
+fn promote_symbol_literal<R: Read>(parser: &Parser<R>, obj: Object) -> Object {
+    if let Some(ObjectVal::StringValue(name)) = &obj._val {
+        if parser.options.nil_is_empty_list && name == "nil" {
+            return create_list_obj(vec![]);
+        }
+        if parser.options.bool_literals && name == "t" {
+            return create_bool_obj(true);
+        }
+    }
+    obj
+}
+
+fn read_expr<R: Read>(parser: &mut Parser<R>) -> Result<Option<Object>, ParseError> {
+    let chr = match parser.get_char(true)? {
+        Some(chr) => chr,
+        None => return Ok(None),
+    };
+
+    if let Some(close) = parser.options.closing_bracket_for(chr) {
+        return Ok(Some(read_list(parser, close)?));
+    }
+
+    match chr {
+        // Parse string skipping trailing and leading " symbol
+        '"' => Ok(Some(read_str(parser)?)),
+        // #t/#f => boolean literals, when the active dialect enables them
+        '#' if parser.options.bool_literals
+            && matches!(parser.peek_char(false)?, Some('t') | Some('f')) =>
+        {
+            let value = parser.get_char(false)? == Some('t');
+            Ok(Some(create_bool_obj(value)))
+        }
+        // 'expr => (quote expr)
+        '\'' => Ok(Some(read_quoted(parser, "quote")?)),
+        // `expr => (quasiquote expr)
+        '`' => Ok(Some(read_quoted(parser, "quasiquote")?)),
+        // ,expr => (unquote expr), ,@expr => (unquote-splicing expr)
+        ',' => {
+            if parser.peek_char(false)? == Some('@') {
+                parser.get_char(false)?;
+                Ok(Some(read_quoted(parser, "unquote-splicing")?))
+            } else {
+                Ok(Some(read_quoted(parser, "unquote")?))
+            }
+        }
+        ')' | ']' | '}' => Err(parser.error(format!("unexpected '{}'", chr))),
+        // A leading sign is a number only if a digit follows; otherwise
+        // it's an ordinary symbol like `-` or `+`.
+        '-' | '+' if matches!(parser.peek_char(false)?, Some(c) if c.is_ascii_digit()) => {
+            Ok(Some(read_number(parser, chr)?))
+        }
+        _ => {
+            if chr.is_numeric() {
+                return Ok(Some(read_number(parser, chr)?));
+            }
+
+            if is_symbol(chr) {
+                let obj = read_symbol(parser, chr)?;
+                return Ok(Some(promote_symbol_literal(parser, obj)));
+            }
+
+            Err(parser.error(format!("invalid symbol: {}", chr)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn single(input: &str) -> Object {
+        let mut exprs = parse_str(input).expect("should parse");
+        assert_eq!(exprs.len(), 1, "expected exactly one expression");
+        exprs.remove(0)
+    }
+
+    #[test]
+    fn parses_decimal_integer() {
+        let obj = single("42");
+        assert!(matches!(obj._type, ObjectType::NUMBER));
+        assert!(matches!(obj._val, Some(ObjectVal::IntegerValue(42))));
+    }
+
+    #[test]
+    fn parses_radix_prefixed_integers() {
+        assert!(matches!(single("0x1A")._val, Some(ObjectVal::IntegerValue(26))));
+        assert!(matches!(single("0o17")._val, Some(ObjectVal::IntegerValue(15))));
+        assert!(matches!(single("0b101")._val, Some(ObjectVal::IntegerValue(5))));
+    }
+
+    #[test]
+    fn parses_negative_radix_prefixed_integer() {
+        assert!(matches!(single("-0x1A")._val, Some(ObjectVal::IntegerValue(-26))));
+    }
+
+    #[test]
+    fn parses_negative_decimal_integer() {
+        assert!(matches!(single("-5")._val, Some(ObjectVal::IntegerValue(-5))));
+    }
+
+    #[test]
+    fn parses_i32_min() {
+        assert!(matches!(
+            single("-2147483648")._val,
+            Some(ObjectVal::IntegerValue(i32::MIN))
+        ));
+        assert!(matches!(
+            single("-0x80000000")._val,
+            Some(ObjectVal::IntegerValue(i32::MIN))
+        ));
+    }
+
+    #[test]
+    fn bare_sign_is_a_symbol() {
+        let obj = single("-");
+        assert!(matches!(obj._type, ObjectType::SYMBOL));
+        match obj._val {
+            Some(ObjectVal::StringValue(s)) => assert_eq!(s, "-"),
+            _ => panic!("expected symbol"),
+        }
+    }
+
+    #[test]
+    fn parses_float_literals() {
+        match single("12.5")._val {
+            Some(ObjectVal::FloatValue(f)) => assert!((f - 12.5).abs() < 1e-9),
+            _ => panic!("expected float"),
+        }
+        match single("-2.5e3")._val {
+            Some(ObjectVal::FloatValue(f)) => assert!((f - (-2500.0)).abs() < 1e-9),
+            _ => panic!("expected float"),
+        }
+    }
+
+    #[test]
+    fn parses_string_escapes() {
+        match single("\"a\\nb\\t\\\"c\"")._val {
+            Some(ObjectVal::StringValue(s)) => assert_eq!(s, "a\nb\t\"c"),
+            _ => panic!("expected string"),
+        }
+        match single("\"\\x41\"")._val {
+            Some(ObjectVal::StringValue(s)) => assert_eq!(s, "A"),
+            _ => panic!("expected string"),
+        }
+        match single("\"\\u{1F600}\"")._val {
+            Some(ObjectVal::StringValue(s)) => assert_eq!(s, "\u{1F600}"),
+            _ => panic!("expected string"),
+        }
+    }
+
+    #[test]
+    fn quote_reader_macros_expand_to_lists() {
+        fn symbols(obj: &Object) -> Vec<String> {
+            match &obj._val {
+                Some(ObjectVal::ListValue(items)) => items
+                    .iter()
+                    .map(|o| match &o._val {
+                        Some(ObjectVal::StringValue(s)) => s.clone(),
+                        _ => String::new(),
+                    })
+                    .collect(),
+                _ => vec![],
+            }
+        }
+
+        assert_eq!(symbols(&single("'x")), vec!["quote", "x"]);
+        assert_eq!(symbols(&single("`x")), vec!["quasiquote", "x"]);
+        assert_eq!(symbols(&single(",x")), vec!["unquote", "x"]);
+        assert_eq!(symbols(&single(",@x")), vec!["unquote-splicing", "x"]);
+    }
+
+    #[test]
+    fn dialect_options_promote_nil_and_bool_literals() {
+        let opts = ParserOptions::default()
+            .with_nil_as_empty_list()
+            .with_bool_literals();
+
+        let mut parser = Parser::with_options("nil".as_bytes(), opts.clone());
+        let nil_obj = parser.read_one().unwrap().unwrap();
+        assert!(matches!(nil_obj._type, ObjectType::LIST));
+        assert!(matches!(nil_obj._val, Some(ObjectVal::ListValue(ref v)) if v.is_empty()));
+
+        let mut parser = Parser::with_options("#t".as_bytes(), opts.clone());
+        let true_obj = parser.read_one().unwrap().unwrap();
+        assert!(matches!(true_obj._val, Some(ObjectVal::IntegerValue(1))));
+
+        let mut parser = Parser::with_options("#f".as_bytes(), opts);
+        let false_obj = parser.read_one().unwrap().unwrap();
+        assert!(matches!(false_obj._val, Some(ObjectVal::IntegerValue(0))));
+    }
+
+    #[test]
+    fn without_dialect_options_nil_is_a_plain_symbol() {
+        let obj = single("nil");
+        assert!(matches!(obj._type, ObjectType::SYMBOL));
+    }
+
+    #[test]
+    fn malformed_utf8_is_a_parse_error_not_a_panic() {
+        let err = parse_reader(&b"\"\xff\""[..]).expect_err("should not panic on bad UTF-8");
+        assert!(err.message.contains("UTF-8"));
+    }
+
+    #[test]
+    fn unterminated_string_is_a_parse_error() {
+        let err = parse_str("\"abc").expect_err("unterminated string should error");
+        assert!(err.message.contains("unterminated"));
+    }
+
+    #[test]
+    fn unterminated_list_is_a_parse_error() {
+        let err = parse_str("(1 2").expect_err("unterminated list should error");
+        assert!(err.message.contains("EOF"));
+    }
+}
+